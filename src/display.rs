@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use ibig::ubig;
+
+use crate::RBig;
+
+impl RBig {
+	/// Renders `self` as a decimal expansion, marking a repeating cycle in parentheses
+	///
+	/// e.g. `1/3` becomes `"0.(3)"`, `1/7` becomes `"0.(142857)"`, `5/4` becomes `"1.25"`. If the
+	/// expansion neither terminates nor starts repeating within `max_digits` fractional digits,
+	/// it is cut off with `"..."`.
+	pub fn to_decimal_string(&self, max_digits: usize) -> String {
+		let reduced = self.clone().reduced();
+
+		// zero has multiple signed representations, but only one of them should ever be printed
+		let mut out = String::new();
+		if reduced.is_negative() {
+			out.push('-');
+		}
+		out.push_str(&reduced.clone().abs_floor().to_string());
+
+		let denom = reduced.denom.as_ref().clone();
+		let mut remainder = reduced.numer % denom.clone();
+		if remainder == ubig!(0) {
+			return out;
+		}
+		out.push('.');
+
+		let mut seen = HashMap::new();
+		let mut digits = String::new();
+		let mut repeat_start = None;
+		for _ in 0..max_digits {
+			if remainder == ubig!(0) {
+				break;
+			}
+			if let Some(&pos) = seen.get(&remainder) {
+				repeat_start = Some(pos);
+				break;
+			}
+			seen.insert(remainder.clone(), digits.len());
+
+			remainder *= 10u8;
+			let digit = remainder.clone() / denom.clone();
+			remainder %= denom.clone();
+			digits.push((u8::try_from(digit).expect("a decimal digit fits in a u8") + b'0') as char);
+		}
+
+		match repeat_start {
+			Some(pos) => {
+				out.push_str(&digits[..pos]);
+				out.push('(');
+				out.push_str(&digits[pos..]);
+				out.push(')');
+			}
+			None => {
+				out.push_str(&digits);
+				if remainder != ubig!(0) {
+					out.push_str("...");
+				}
+			}
+		}
+
+		out
+	}
+}
+
+impl fmt::Display for RBig {
+	/// Renders `self` as a decimal expansion; see [`to_decimal_string`](Self::to_decimal_string)
+	///
+	/// Every rational's decimal expansion either terminates or starts repeating within at most
+	/// `denom` digits, so this never truncates with `"..."`.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.to_decimal_string(usize::MAX))
+	}
+}
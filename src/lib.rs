@@ -17,9 +17,13 @@ use crate::util::*;
 pub mod reexport {
 	pub use ibig;
 }
+mod display;
 pub mod nonzero_ubig;
 #[cfg(feature = "num-traits-impls")]
 mod num_traits_impls;
+pub mod parse;
+#[cfg(feature = "serde")]
+mod serde_impls;
 mod util;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
@@ -149,6 +153,76 @@ impl From<IBig> for RBig {
 	}
 }
 
+/// lossless conversions from floating-point types
+impl RBig {
+	/// Constructs the exact rational value of a finite `f64`
+	///
+	/// Returns `None` for `NaN` and `±∞`. Every other `f64` is a dyadic rational
+	/// (`±m·2^e` for a 53-bit integer `m` and an exponent `e`), so the conversion is always exact.
+	pub fn from_f64(f: f64) -> Option<Self> {
+		let bits = f.to_bits();
+		let sign = Sign::positive_if(bits >> 63 == 0);
+		let biased_exp = (bits >> 52) & 0x7ff;
+		let mantissa = bits & 0xf_ffff_ffff_ffff;
+
+		if biased_exp == 0x7ff {
+			// NaN or infinite
+			return None;
+		}
+		if biased_exp == 0 && mantissa == 0 {
+			return Some(Self::from(ubig!(0)));
+		}
+
+		// value = ±m * 2^e
+		let (m, e) = if biased_exp == 0 {
+			// subnormal
+			(mantissa, -1074i64)
+		} else {
+			(mantissa | (1 << 52), biased_exp as i64 - 1075)
+		};
+		let m = UBig::from(m);
+
+		Some(if e >= 0 {
+			Self::new(sign, m << e as usize, NonZeroUBig::one())
+		} else {
+			// SAFETY: `1 << -e` is a power of two, so it is never zero
+			let denom = unsafe { NonZeroUBig::new_unchecked(ubig!(1) << (-e) as usize) };
+			Self::new(sign, m, denom)
+		})
+	}
+
+	/// Constructs the exact rational value of a finite `f32`
+	///
+	/// See [`from_f64`](Self::from_f64) for details; the only difference is the bit layout of `f32`.
+	pub fn from_f32(f: f32) -> Option<Self> {
+		let bits = f.to_bits();
+		let sign = Sign::positive_if(bits >> 31 == 0);
+		let biased_exp = (bits >> 23) & 0xff;
+		let mantissa = bits & 0x7f_ffff;
+
+		if biased_exp == 0xff {
+			return None;
+		}
+		if biased_exp == 0 && mantissa == 0 {
+			return Some(Self::from(ubig!(0)));
+		}
+
+		let (m, e) = if biased_exp == 0 {
+			(mantissa, -149i64)
+		} else {
+			(mantissa | (1 << 23), biased_exp as i64 - 150)
+		};
+		let m = UBig::from(m);
+
+		Some(if e >= 0 {
+			Self::new(sign, m << e as usize, NonZeroUBig::one())
+		} else {
+			let denom = unsafe { NonZeroUBig::new_unchecked(ubig!(1) << (-e) as usize) };
+			Self::new(sign, m, denom)
+		})
+	}
+}
+
 /// helper functions
 impl RBig {
 	fn cross_mul_abs(self, rhs: RBig) -> Pair<UBig> {
@@ -169,6 +243,19 @@ impl RBig {
 			Sign::Negative => LogicalSignum::Neg,
 		}
 	}
+
+	/// Returns whichever of `a`, `b` is closer to `self`, breaking ties towards the smaller denominator
+	fn nearer_of(&self, a: Self, b: Self) -> Self {
+		// the subtractions go through `cross_mul_signed` and the subsequent `cmp` through `cross_mul_abs`
+		let diff_a = (self.clone() - a.clone()).abs();
+		let diff_b = (self.clone() - b.clone()).abs();
+		match diff_a.cmp(&diff_b) {
+			Ordering::Less => a,
+			Ordering::Greater => b,
+			Ordering::Equal if a.denom.as_ref() <= b.denom.as_ref() => a,
+			Ordering::Equal => b,
+		}
+	}
 }
 
 impl PartialEq for RBig {
@@ -229,7 +316,10 @@ impl Hash for RBig {
 // red is sus
 #[allow(clippy::suspicious_arithmetic_impl, clippy::suspicious_op_assign_impl)]
 mod arith_impls {
-	use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+	use std::iter::{Product, Sum};
+	use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+
+	use ibig::ubig;
 
 	use crate::RBig;
 
@@ -321,8 +411,34 @@ mod arith_impls {
 		}
 	}
 
+	impl Rem for RBig {
+		type Output = Self;
+
+		fn rem(self, rhs: Self) -> Self::Output {
+			let quotient = (self.clone() / rhs.clone()).trunc();
+			self - quotient * rhs
+		}
+	}
+
+	impl RemAssign for RBig {
+		fn rem_assign(&mut self, rhs: Self) {
+			*self = self.clone() % rhs;
+		}
+	}
+
+	impl Sum for RBig {
+		fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+			iter.fold(Self::from(ubig!(0)), Add::add)
+		}
+	}
+
+	impl Product for RBig {
+		fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+			iter.fold(Self::from(ubig!(1)), Mul::mul)
+		}
+	}
+
 	// todo: arithmetic with IBig, UBig and with machine integer types
-	// todo: `Product`, `Sum` impls
 }
 
 impl RBig {
@@ -598,3 +714,200 @@ impl RBig {
 		Self::new(self.sign, self.numer % self.denom.clone().get(), self.denom)
 	}
 }
+
+impl RBig {
+	/// Returns the closest rational to `self` whose reduced denominator does not exceed `max_denom`
+	///
+	/// This uses the continued-fraction (Stern-Brocot) expansion of `self`: it walks the
+	/// convergents `h_i / k_i` (with `h_i = a_i·h_{i-1} + h_{i-2}` and likewise for `k_i`) until
+	/// the denominator would exceed `max_denom`, then forms the best semiconvergent at that step
+	/// (the largest partial quotient that keeps `k_i <= max_denom`) and returns whichever of that
+	/// semiconvergent and the previous convergent is closer to `self`, breaking ties towards the
+	/// smaller denominator.
+	///
+	/// # Panics
+	/// Panics if `max_denom` is zero.
+	pub fn approximate(&self, max_denom: &UBig) -> Self {
+		assert_ne!(*max_denom, ubig!(0), "tried to approximate with a max_denom of zero");
+
+		let reduced = self.clone().reduced();
+		if reduced.denom.as_ref() <= max_denom {
+			return reduced;
+		}
+
+		let mut n = reduced.numer.clone();
+		let mut d = reduced.denom.clone().get();
+
+		let (mut h_prev2, mut h_prev1) = (ubig!(0), ubig!(1));
+		let (mut k_prev2, mut k_prev1) = (ubig!(1), ubig!(0));
+
+		loop {
+			let a = n.clone() / d.clone();
+			let r = n % d.clone();
+
+			let h = a.clone() * h_prev1.clone() + h_prev2.clone();
+			let k = a * k_prev1.clone() + k_prev2.clone();
+
+			if k > *max_denom {
+				// the denominator seed `k_prev2 = 1` means `k` is `1` (hence `<= max_denom`) on
+				// the very first iteration, so `k_prev1` is always nonzero by the time we get here
+				let t = (max_denom.clone() - k_prev2.clone()) / k_prev1.clone();
+				let h_semi = t.clone() * h_prev1.clone() + h_prev2;
+				let k_semi = t * k_prev1.clone() + k_prev2;
+
+				// SAFETY: either `k_prev2 >= 1`, or `k_prev2 == 0` and `k_prev1 == 1` (second
+				// iteration), in which case `t == max_denom >= 1`; so `k_semi` is nonzero
+				let semi = Self::new(reduced.sign, h_semi, unsafe {
+					NonZeroUBig::new_unchecked(k_semi)
+				});
+				// SAFETY: `k_prev1` is nonzero, as established above
+				let prev = Self::new(reduced.sign, h_prev1, unsafe {
+					NonZeroUBig::new_unchecked(k_prev1)
+				});
+
+				return reduced.nearer_of(semi, prev);
+			}
+
+			h_prev2 = h_prev1;
+			h_prev1 = h;
+			k_prev2 = k_prev1;
+			k_prev1 = k;
+
+			if r == ubig!(0) {
+				// SAFETY: `k_prev1` is nonzero, as established above
+				return Self::new(reduced.sign, h_prev1, unsafe {
+					NonZeroUBig::new_unchecked(k_prev1)
+				});
+			}
+
+			n = d;
+			d = r;
+		}
+	}
+}
+
+/// correctly-rounded conversions to floating-point types
+impl RBig {
+	/// Rounds the (nonnegative) magnitude of `self` to `bits` significant bits, half to even
+	///
+	/// Returns `(mantissa, exp)` such that `self ~= mantissa * 2^exp`. `mantissa`'s bit length is
+	/// normally `bits`, but may be `bits + 1` if rounding carried into the next power of two.
+	fn round_to_bits(&self, bits: usize) -> (UBig, i64) {
+		let bn = self.numer.bit_len() as i64;
+		let bd = self.denom.as_ref().bit_len() as i64;
+		let mut shift = bits as i64 - 1 - bn + bd;
+
+		loop {
+			let (numer, denom) = if shift >= 0 {
+				(self.numer.clone() << shift as usize, self.denom.clone().get())
+			} else {
+				(self.numer.clone(), self.denom.clone().get() << (-shift) as usize)
+			};
+
+			let quotient_bits = (numer.clone() / denom.clone()).bit_len();
+			if quotient_bits < bits {
+				shift += 1;
+				continue;
+			}
+			if quotient_bits > bits {
+				shift -= 1;
+				continue;
+			}
+
+			// SAFETY: `denom` is `self.denom` (nonzero) shifted left, which stays nonzero
+			let scaled = Self::new(Sign::Positive, numer, unsafe {
+				NonZeroUBig::new_unchecked(denom)
+			});
+			let mantissa = scaled
+				.round(rounding::TowardNearest {
+					tie_breaker: rounding::TowardNearestEven,
+				})
+				.unsigned_abs();
+			return (mantissa, -shift);
+		}
+	}
+
+	/// Returns the nearest `f64` to `self`, rounding half to even
+	///
+	/// Returns `±∞` if `self` is too large to represent, and a signed zero (matching `self.sign`)
+	/// if `self` is zero.
+	pub fn to_f64(&self) -> f64 {
+		let reduced = self.clone().reduced();
+		if reduced.is_zero() {
+			return if reduced.sign.is_positive() { 0.0 } else { -0.0 };
+		}
+
+		let (mantissa, exp) = reduced.round_to_bits(53);
+		let unbiased = 52 + exp;
+		let (mantissa, unbiased) = if mantissa.bit_len() == 54 {
+			(mantissa >> 1usize, unbiased + 1)
+		} else {
+			(mantissa, unbiased)
+		};
+
+		if unbiased >= 1024 {
+			return if reduced.sign.is_positive() {
+				f64::INFINITY
+			} else {
+				f64::NEG_INFINITY
+			};
+		}
+
+		let (biased_exp, mantissa_bits) = if unbiased >= -1022 {
+			((unbiased + 1023) as u64, mantissa - (ubig!(1) << 52usize))
+		} else {
+			// subnormal: the exponent field is stuck at zero and the value is `mantissa * 2^-1074`,
+			// so `self * 2^1074` is rounded to an integer directly; a result of `2^52` sets the
+			// lowest exponent bit, which is exactly the smallest normal number
+			let scaled = Self::new(Sign::Positive, reduced.numer.clone() << 1074usize, reduced.denom.clone());
+			let mantissa = scaled
+				.round(rounding::TowardNearest { tie_breaker: rounding::TowardNearestEven })
+				.unsigned_abs();
+			(0u64, mantissa)
+		};
+
+		let mantissa_bits: u64 = mantissa_bits.try_into().expect("a float's mantissa fits in a u64");
+		let sign_bit = u64::from(!reduced.sign.is_positive()) << 63;
+		f64::from_bits(sign_bit | (biased_exp << 52) | mantissa_bits)
+	}
+
+	/// Returns the nearest `f32` to `self`, rounding half to even
+	///
+	/// See [`to_f64`](Self::to_f64) for details; the only difference is the bit layout of `f32`.
+	pub fn to_f32(&self) -> f32 {
+		let reduced = self.clone().reduced();
+		if reduced.is_zero() {
+			return if reduced.sign.is_positive() { 0.0 } else { -0.0 };
+		}
+
+		let (mantissa, exp) = reduced.round_to_bits(24);
+		let unbiased = 23 + exp;
+		let (mantissa, unbiased) = if mantissa.bit_len() == 25 {
+			(mantissa >> 1usize, unbiased + 1)
+		} else {
+			(mantissa, unbiased)
+		};
+
+		if unbiased >= 128 {
+			return if reduced.sign.is_positive() {
+				f32::INFINITY
+			} else {
+				f32::NEG_INFINITY
+			};
+		}
+
+		let (biased_exp, mantissa_bits) = if unbiased >= -126 {
+			((unbiased + 127) as u32, mantissa - (ubig!(1) << 23usize))
+		} else {
+			let scaled = Self::new(Sign::Positive, reduced.numer.clone() << 149usize, reduced.denom.clone());
+			let mantissa = scaled
+				.round(rounding::TowardNearest { tie_breaker: rounding::TowardNearestEven })
+				.unsigned_abs();
+			(0u32, mantissa)
+		};
+
+		let mantissa_bits: u32 = mantissa_bits.try_into().expect("a float's mantissa fits in a u32");
+		let sign_bit = u32::from(!reduced.sign.is_positive()) << 31;
+		f32::from_bits(sign_bit | (biased_exp << 23) | mantissa_bits)
+	}
+}
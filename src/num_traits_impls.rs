@@ -1,6 +1,10 @@
-use ibig::ubig;
-use num_traits::{One, Pow, Zero};
+use ibig::{ubig, IBig, UBig};
+use num_traits::{
+	CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, FromPrimitive, Inv, Num, One, Pow, Signed,
+	ToPrimitive, Zero,
+};
 
+use crate::parse::ParseRBigError;
 use crate::RBig;
 
 impl Zero for RBig {
@@ -38,4 +42,116 @@ impl Pow<usize> for RBig {
 	}
 }
 
-// todo: impl Checked*, {To,From}Primitive, Inv
\ No newline at end of file
+impl FromPrimitive for RBig {
+	fn from_i64(n: i64) -> Option<Self> {
+		Some(Self::from(IBig::from(n)))
+	}
+
+	fn from_u64(n: u64) -> Option<Self> {
+		Some(Self::from(UBig::from(n)))
+	}
+
+	fn from_f32(n: f32) -> Option<Self> {
+		Self::from_f32(n)
+	}
+
+	fn from_f64(n: f64) -> Option<Self> {
+		Self::from_f64(n)
+	}
+}
+
+impl ToPrimitive for RBig {
+	fn to_i64(&self) -> Option<i64> {
+		i64::try_from(&self.try_to_int()?).ok()
+	}
+
+	fn to_u64(&self) -> Option<u64> {
+		u64::try_from(&self.try_to_uint()?).ok()
+	}
+
+	fn to_f32(&self) -> Option<f32> {
+		Some(self.to_f32())
+	}
+
+	fn to_f64(&self) -> Option<f64> {
+		Some(self.to_f64())
+	}
+}
+
+impl Num for RBig {
+	type FromStrRadixErr = ParseRBigError;
+
+	fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+		Self::from_str_radix(str, radix)
+	}
+}
+
+impl Signed for RBig {
+	fn abs(&self) -> Self {
+		self.clone().abs()
+	}
+
+	fn abs_sub(&self, other: &Self) -> Self {
+		if self <= other {
+			Self::zero()
+		} else {
+			self.clone() - other.clone()
+		}
+	}
+
+	fn signum(&self) -> Self {
+		self.signum()
+	}
+
+	fn is_positive(&self) -> bool {
+		self.is_positive()
+	}
+
+	fn is_negative(&self) -> bool {
+		self.is_negative()
+	}
+}
+
+impl Inv for RBig {
+	type Output = Self;
+
+	fn inv(self) -> Self::Output {
+		self.recip()
+	}
+}
+
+impl CheckedAdd for RBig {
+	fn checked_add(&self, v: &Self) -> Option<Self> {
+		Some(self.clone() + v.clone())
+	}
+}
+
+impl CheckedSub for RBig {
+	fn checked_sub(&self, v: &Self) -> Option<Self> {
+		Some(self.clone() - v.clone())
+	}
+}
+
+impl CheckedMul for RBig {
+	fn checked_mul(&self, v: &Self) -> Option<Self> {
+		Some(self.clone() * v.clone())
+	}
+}
+
+impl CheckedDiv for RBig {
+	fn checked_div(&self, v: &Self) -> Option<Self> {
+		if v.is_zero() {
+			return None;
+		}
+		Some(self.clone() / v.clone())
+	}
+}
+
+impl CheckedRem for RBig {
+	fn checked_rem(&self, v: &Self) -> Option<Self> {
+		if v.is_zero() {
+			return None;
+		}
+		Some(self.clone() % v.clone())
+	}
+}
\ No newline at end of file
@@ -0,0 +1,107 @@
+use std::fmt;
+use std::str::FromStr;
+
+use ibig::error::ParseError as IBigParseError;
+use ibig::{ubig, UBig};
+
+use crate::nonzero_ubig::NonZeroUBig;
+use crate::{RBig, Sign};
+
+/// Error returned by [`RBig::from_str_radix`] (and, transitively, [`FromStr`] and
+/// [`TryFrom<&str>`](TryFrom) for [`RBig`])
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ParseRBigError {
+	/// one of the integer sub-strings (the bare integer, or the numerator/denominator/integer
+	/// and fractional parts of a fraction or decimal) failed to parse
+	Int(IBigParseError),
+	/// the explicit denominator of a fraction was zero (the denominator implied by a decimal's
+	/// fractional digits is a power of the radix and never zero)
+	ZeroDenominator,
+}
+
+impl From<IBigParseError> for ParseRBigError {
+	#[inline]
+	fn from(e: IBigParseError) -> Self {
+		Self::Int(e)
+	}
+}
+
+impl fmt::Display for ParseRBigError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Int(e) => fmt::Display::fmt(e, f),
+			Self::ZeroDenominator => f.write_str("zero denominator"),
+		}
+	}
+}
+
+impl std::error::Error for ParseRBigError {}
+
+impl RBig {
+	/// Parses an `RBig` from a string in a given radix
+	///
+	/// Accepts three shapes, each with an optional leading `+`/`-` sign:
+	/// - a bare integer, e.g. `"-42"`
+	/// - a fraction, e.g. `"-3/4"`
+	/// - a decimal, e.g. `"-12.375"`, whose fractional digits become the numerator over a
+	///   denominator of `radix` to the power of the number of fractional digits
+	///   (e.g. `"-12.375"` becomes `-12375/1000`, which is `-99/8` once [`reduce`](Self::reduce)d)
+	///
+	/// The result is not reduced; call [`reduce`](Self::reduce) on it if needed.
+	///
+	/// # Panics
+	/// Panics if `radix` is not between 2 and 36 inclusive.
+	pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseRBigError> {
+		let (sign, rest) = match src.strip_prefix('-') {
+			Some(rest) => (Sign::Negative, rest),
+			None => (Sign::Positive, src.strip_prefix('+').unwrap_or(src)),
+		};
+
+		if let Some((int_part, frac_part)) = rest.split_once('.') {
+			let int_part = if int_part.is_empty() {
+				ubig!(0)
+			} else {
+				UBig::from_str_radix(int_part, radix)?
+			};
+			let frac_digits = if frac_part.is_empty() {
+				ubig!(0)
+			} else {
+				UBig::from_str_radix(frac_part, radix)?
+			};
+			let scale = UBig::from(radix).pow(frac_part.len());
+			let numer = int_part * scale.clone() + frac_digits;
+			// `scale` is never zero: with no fractional digits it is `radix^0 == 1`, and otherwise
+			// `frac_part` went through `UBig::from_str_radix`, which checks that `radix` is at least 2
+			let denom = unsafe { NonZeroUBig::new_unchecked(scale) };
+			return Ok(Self::new(sign, numer, denom));
+		}
+
+		if let Some((numer_part, denom_part)) = rest.split_once('/') {
+			let numer = UBig::from_str_radix(numer_part, radix)?;
+			let denom = UBig::from_str_radix(denom_part, radix)?;
+			let denom = NonZeroUBig::new(denom).ok_or(ParseRBigError::ZeroDenominator)?;
+			return Ok(Self::new(sign, numer, denom));
+		}
+
+		let numer = UBig::from_str_radix(rest, radix)?;
+		Ok(Self::new(sign, numer, NonZeroUBig::one()))
+	}
+}
+
+impl FromStr for RBig {
+	type Err = ParseRBigError;
+
+	#[inline]
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::from_str_radix(s, 10)
+	}
+}
+
+impl TryFrom<&str> for RBig {
+	type Error = ParseRBigError;
+
+	#[inline]
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		s.parse()
+	}
+}
@@ -0,0 +1,70 @@
+use ibig::UBig;
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use crate::nonzero_ubig::NonZeroUBig;
+use crate::{RBig, Sign};
+
+impl Serialize for Sign {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.is_positive().serialize(serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for Sign {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let is_positive = bool::deserialize(deserializer)?;
+		Ok(if is_positive { Sign::Positive } else { Sign::Negative })
+	}
+}
+
+impl Serialize for NonZeroUBig {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.as_ref().serialize(serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for NonZeroUBig {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let u = UBig::deserialize(deserializer)?;
+		NonZeroUBig::new(u).ok_or_else(|| de::Error::custom("denominator must not be zero"))
+	}
+}
+
+/// The human-readable wire format of [`RBig`]: its sign and the absolute values of its numerator
+/// and denominator, spelled out as separate fields
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RBigFields {
+	sign: Sign,
+	numer: UBig,
+	denom: NonZeroUBig,
+}
+
+impl Serialize for RBig {
+	/// Serializes as a `{sign, numer, denom}` struct for human-readable formats, or as a compact
+	/// `numer/denom` fraction string otherwise
+	///
+	/// The compact form is a fraction, not the decimal expansion [`Display`](std::fmt::Display)
+	/// produces, so that it always round-trips through [`FromStr`](std::str::FromStr).
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		if serializer.is_human_readable() {
+			RBigFields { sign: self.sign, numer: self.numer.clone(), denom: self.denom.clone() }
+				.serialize(serializer)
+		} else {
+			let sign = if self.sign.is_positive() { "" } else { "-" };
+			format!("{sign}{}/{}", self.numer, self.denom.as_ref()).serialize(serializer)
+		}
+	}
+}
+
+impl<'de> Deserialize<'de> for RBig {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		if deserializer.is_human_readable() {
+			let fields = RBigFields::deserialize(deserializer)?;
+			Ok(RBig::new(fields.sign, fields.numer, fields.denom))
+		} else {
+			let s = String::deserialize(deserializer)?;
+			s.parse().map_err(de::Error::custom)
+		}
+	}
+}